@@ -1,6 +1,6 @@
 #[derive(Debug, PartialEq)]
-pub enum EvaluationResult {
-    Success(i32),
+pub enum EvaluationResult<T = i32> {
+    Success(T),
     InputEmpty,
     InputNotComplete,
     InvalidCharacterFound(char),
@@ -8,37 +8,23 @@ pub enum EvaluationResult {
     FoundNonDigit(char),
     InputNumberOverflow,
     DivByZero,
+    StackUnderflow,
+    MismatchedParentheses,
+    InvalidDigitForRadix(char),
     Overflow {
-        last_valid_value1: i32,
-        last_valid_value2: i32,
+        last_valid_value1: T,
+        last_valid_value2: T,
         attempted_operation: RpnOperator,
     },
 }
 
-#[derive(Debug, PartialEq)]
-enum EvaluationStep {
-    ReadingValue1,
-    ReadingValue2,
-    ReadingOperator,
-}
-
-impl EvaluationStep {
-    pub fn advance(&mut self) {
-        use EvaluationStep as S;
-        *self = match self {
-            S::ReadingValue1 => S::ReadingValue2,
-            S::ReadingValue2 => S::ReadingOperator,
-            S::ReadingOperator => S::ReadingValue2,
-        };
-    }
-}
-
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RpnOperator {
     Addition,
     Subtraction,
     Multiplication,
     Division,
+    Modulo,
 }
 
 impl TryFrom<char> for RpnOperator {
@@ -50,6 +36,59 @@ impl TryFrom<char> for RpnOperator {
             '-' => Ok(RpnOperator::Subtraction),
             '*' => Ok(RpnOperator::Multiplication),
             '/' => Ok(RpnOperator::Division),
+            '%' => Ok(RpnOperator::Modulo),
+            _ => Err(EvaluationResult::FoundNonOperator(value)),
+        }
+    }
+}
+
+impl RpnOperator {
+    /// Higher binds tighter; `*`/`/`/`%` over `+`/`-`. All operators are left-associative.
+    fn precedence(&self) -> u8 {
+        match self {
+            RpnOperator::Addition | RpnOperator::Subtraction => 1,
+            RpnOperator::Multiplication | RpnOperator::Division | RpnOperator::Modulo => 2,
+        }
+    }
+}
+
+/// A unary operator consumed from a multi-character token (`neg`, `abs`), since
+/// the single-character token space is already spoken for by the binary operators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnaryOp {
+    Neg,
+    Abs,
+}
+
+impl TryFrom<&str> for UnaryOp {
+    type Error = EvaluationResult;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "neg" => Ok(UnaryOp::Neg),
+            "abs" => Ok(UnaryOp::Abs),
+            _ => Err(EvaluationResult::InvalidCharacterFound(
+                value.chars().next().unwrap_or('?'),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CmpOp {
+    LessThan,
+    GreaterThan,
+    Equal,
+}
+
+impl TryFrom<char> for CmpOp {
+    type Error = EvaluationResult;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '<' => Ok(CmpOp::LessThan),
+            '>' => Ok(CmpOp::GreaterThan),
+            '=' => Ok(CmpOp::Equal),
             _ => Err(EvaluationResult::FoundNonOperator(value)),
         }
     }
@@ -61,119 +100,523 @@ pub trait IntoRpnOperator {
 
 impl IntoRpnOperator for char {
     fn is_valid_rpn_operator(&self) -> bool {
-        match RpnOperator::try_from(*self) {
-            Ok(_) => true,
-            Err(_) => false,
+        RpnOperator::try_from(*self).is_ok()
+    }
+}
+
+/// Selects how arithmetic handles values that don't fit in the evaluated integer
+/// type (`i32` or `i64`): error out, wrap around modulo the type's range, or clamp
+/// to the type's `MIN`/`MAX`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverflowMode {
+    Checked,
+    Wrapping,
+    Saturating,
+}
+
+/// A whole-number type the evaluator can run its stack machine over (`i32`, `i64`, ...),
+/// bundling the digit/radix parsing and the checked/wrapping/saturating arithmetic
+/// `OverflowMode` needs so `evaluate_rpn_generic` only has to be written once.
+pub trait RpnInt: Copy + PartialEq {
+    fn zero() -> Self;
+    fn from_digit(digit: u32) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn wrapping_div(self, rhs: Self) -> Self;
+    fn wrapping_rem(self, rhs: Self) -> Self;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+    fn saturating_div(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_rpn_int {
+    ($t:ty) => {
+        impl RpnInt for $t {
+            fn zero() -> Self {
+                0
+            }
+            fn from_digit(digit: u32) -> Self {
+                digit as $t
+            }
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$t>::wrapping_mul(self, rhs)
+            }
+            fn wrapping_div(self, rhs: Self) -> Self {
+                <$t>::wrapping_div(self, rhs)
+            }
+            fn wrapping_rem(self, rhs: Self) -> Self {
+                <$t>::wrapping_rem(self, rhs)
+            }
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$t>::saturating_add(self, rhs)
+            }
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$t>::saturating_sub(self, rhs)
+            }
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$t>::saturating_mul(self, rhs)
+            }
+            fn saturating_div(self, rhs: Self) -> Self {
+                <$t>::saturating_div(self, rhs)
+            }
+        }
+    };
+}
+
+impl_rpn_int!(i32);
+impl_rpn_int!(i64);
+
+/// Splits a number token into its radix and the digits that follow, recognizing
+/// the `0x`/`0o`/`0b` prefixes for hex, octal, and binary literals. The `bool`
+/// reports whether a prefix was actually stripped, so callers can tell a bare
+/// base-10 typo from a malformed radix literal.
+fn radix_of(token: &str) -> (u32, &str, bool) {
+    for (prefix, base) in [("0x", 16), ("0X", 16), ("0o", 8), ("0O", 8), ("0b", 2), ("0B", 2)] {
+        if let Some(digits) = token.strip_prefix(prefix) {
+            return (base, digits, true);
         }
     }
+    (10, token, false)
 }
 
-pub fn evaluate_rpn(input: &str) -> EvaluationResult {
+fn parse_number<T: RpnInt>(token: &str, mode: OverflowMode) -> Result<T, EvaluationResult<T>> {
+    use EvaluationResult as ER;
+    use OverflowMode as M;
+
+    let (base, digits, has_prefix) = radix_of(token);
+
+    if has_prefix && digits.is_empty() {
+        return Err(ER::InvalidDigitForRadix(token.chars().nth(1).unwrap()));
+    }
+
+    let base_value = T::from_digit(base);
+    let mut value = T::zero();
+    for c in digits.chars() {
+        let digit = match c.to_digit(base) {
+            Some(d) => T::from_digit(d),
+            None if has_prefix => return Err(ER::InvalidDigitForRadix(c)),
+            None if c.is_ascii_alphanumeric() || c.is_valid_rpn_operator() => {
+                return Err(ER::FoundNonDigit(c))
+            }
+            None => return Err(ER::InvalidCharacterFound(c)),
+        };
+        value = match mode {
+            M::Checked => match value.checked_mul(base_value) {
+                Some(value) => match value.checked_add(digit) {
+                    Some(value) => value,
+                    None => return Err(ER::InputNumberOverflow),
+                },
+                None => return Err(ER::InputNumberOverflow),
+            },
+            M::Wrapping => value.wrapping_mul(base_value).wrapping_add(digit),
+            M::Saturating => value.saturating_mul(base_value).saturating_add(digit),
+        };
+    }
+    Ok(value)
+}
+
+fn apply_operator<T: RpnInt>(
+    o: RpnOperator,
+    lhs: T,
+    rhs: T,
+    mode: OverflowMode,
+) -> Result<T, EvaluationResult<T>> {
+    use EvaluationResult as ER;
+    use OverflowMode as M;
+    use RpnOperator as OP;
+
+    if matches!(o, OP::Division | OP::Modulo) && rhs == T::zero() {
+        return Err(ER::DivByZero);
+    }
+
+    match mode {
+        M::Checked => {
+            let result = match o {
+                OP::Addition => lhs.checked_add(rhs),
+                OP::Subtraction => lhs.checked_sub(rhs),
+                OP::Multiplication => lhs.checked_mul(rhs),
+                OP::Division => lhs.checked_div(rhs),
+                OP::Modulo => lhs.checked_rem(rhs),
+            };
+            result.ok_or(ER::Overflow {
+                last_valid_value1: lhs,
+                last_valid_value2: rhs,
+                attempted_operation: o,
+            })
+        }
+        M::Wrapping => Ok(match o {
+            OP::Addition => lhs.wrapping_add(rhs),
+            OP::Subtraction => lhs.wrapping_sub(rhs),
+            OP::Multiplication => lhs.wrapping_mul(rhs),
+            OP::Division => lhs.wrapping_div(rhs),
+            OP::Modulo => lhs.wrapping_rem(rhs),
+        }),
+        M::Saturating => Ok(match o {
+            OP::Addition => lhs.saturating_add(rhs),
+            OP::Subtraction => lhs.saturating_sub(rhs),
+            OP::Multiplication => lhs.saturating_mul(rhs),
+            OP::Division => lhs.saturating_div(rhs),
+            OP::Modulo => lhs.wrapping_rem(rhs),
+        }),
+    }
+}
+
+/// The stack machine shared by every RPN evaluator: tokenize on whitespace, pop two
+/// operands for each operator token, and delegate the actual parsing/arithmetic to
+/// `parse_num`/`apply_op` so integer evaluators (with `OverflowMode` and radix
+/// parsing) and the `f64` evaluator (with none of that) share one tokenize/dispatch
+/// implementation instead of drifting apart.
+fn run_stack_machine<T: Copy>(
+    input: &str,
+    mut apply_op: impl FnMut(RpnOperator, T, T) -> Result<T, EvaluationResult<T>>,
+    mut parse_num: impl FnMut(&str) -> Result<T, EvaluationResult<T>>,
+) -> EvaluationResult<T> {
     use EvaluationResult as ER;
-    use EvaluationStep as S;
+
     let input = input.trim();
+    if input.is_empty() {
+        return ER::InputEmpty;
+    }
+
+    let mut stack: Vec<T> = Vec::new();
+
+    for token in input.split_whitespace() {
+        let mut chars = token.chars();
+        let first = chars.next().unwrap();
+
+        if token.len() == 1 && first.is_valid_rpn_operator() {
+            let o = RpnOperator::try_from(first).unwrap();
+            let rhs = match stack.pop() {
+                Some(v) => v,
+                None => return ER::StackUnderflow,
+            };
+            let lhs = match stack.pop() {
+                Some(v) => v,
+                None => return ER::StackUnderflow,
+            };
+            match apply_op(o, lhs, rhs) {
+                Ok(result) => stack.push(result),
+                Err(e) => return e,
+            }
+        } else {
+            match parse_num(token) {
+                Ok(value) => stack.push(value),
+                Err(e) => return e,
+            }
+        }
+    }
+
+    match stack.len() {
+        1 => ER::Success(stack[0]),
+        _ => ER::InputNotComplete,
+    }
+}
+
+/// The integer evaluator generalized over any `RpnInt` and `OverflowMode`, so
+/// `i32`/`i64` and all three overflow policies share one implementation.
+fn evaluate_rpn_generic<T: RpnInt>(input: &str, mode: OverflowMode) -> EvaluationResult<T> {
+    run_stack_machine(
+        input,
+        |o, lhs, rhs| apply_operator(o, lhs, rhs, mode),
+        |token| parse_number(token, mode),
+    )
+}
+
+/// Evaluates `input` as RPN over `i32`, always treating overflow as an error.
+///
+/// Equivalent to `evaluate_rpn_with(input, OverflowMode::Checked)`.
+pub fn evaluate_rpn(input: &str) -> EvaluationResult {
+    evaluate_rpn_with(input, OverflowMode::Checked)
+}
+
+/// Evaluates `input` as RPN over `i32` under the given `OverflowMode`, trading the
+/// default checked-arithmetic errors for wrapping or saturating semantics where useful.
+pub fn evaluate_rpn_with(input: &str, mode: OverflowMode) -> EvaluationResult {
+    evaluate_rpn_generic::<i32>(input, mode)
+}
+
+/// Evaluates `input` as RPN over `i64` instead of `i32`, for expressions that
+/// outgrow 32 bits but still want checked (error-on-overflow) arithmetic.
+pub fn evaluate_rpn_i64(input: &str) -> EvaluationResult<i64> {
+    evaluate_rpn_i64_with(input, OverflowMode::Checked)
+}
+
+/// Evaluates `input` as RPN over `i64` under the given `OverflowMode`, mirroring
+/// `evaluate_rpn_with` for callers who need the wider integer type.
+pub fn evaluate_rpn_i64_with(input: &str, mode: OverflowMode) -> EvaluationResult<i64> {
+    evaluate_rpn_generic::<i64>(input, mode)
+}
+
+fn parse_f64_token(token: &str) -> Result<f64, EvaluationResult<f64>> {
+    use EvaluationResult as ER;
+
+    let mut seen_dot = false;
+    for c in token.chars() {
+        if c == '.' {
+            if seen_dot {
+                return Err(ER::InvalidCharacterFound(c));
+            }
+            seen_dot = true;
+        } else if !c.is_ascii_digit() {
+            return Err(ER::FoundNonDigit(c));
+        }
+    }
+
+    token.parse::<f64>().map_err(|_| ER::InvalidCharacterFound('.'))
+}
+
+fn apply_operator_f64(o: RpnOperator, lhs: f64, rhs: f64) -> f64 {
+    use RpnOperator as OP;
 
-    let mut value1: i32 = 0;
-    let mut value2: i32 = 0;
-    let mut step = S::ReadingValue1;
+    match o {
+        OP::Addition => lhs + rhs,
+        OP::Subtraction => lhs - rhs,
+        OP::Multiplication => lhs * rhs,
+        OP::Division => lhs / rhs,
+        OP::Modulo => lhs % rhs,
+    }
+}
+
+/// Evaluates `input` as RPN over `f64`, accepting decimal literals like `3.5`.
+///
+/// Unlike the integer evaluators, division by zero is not an error here: it
+/// follows IEEE 754 and produces infinity or NaN, matching native `f64` division.
+pub fn evaluate_rpn_f64(input: &str) -> EvaluationResult<f64> {
+    run_stack_machine(
+        input,
+        |o, lhs, rhs| Ok(apply_operator_f64(o, lhs, rhs)),
+        parse_f64_token,
+    )
+}
 
-    for c in input.chars() {
-        match c {
-            ' ' => {
-                step.advance();
-                if step == S::ReadingValue2 {
-                    value2 = 0;
+fn tokenize_infix(input: &str) -> Result<Vec<String>, EvaluationResult> {
+    use EvaluationResult as ER;
+
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() {
+            let mut number = String::new();
+            while let Some(&d) = chars.peek() {
+                if !d.is_ascii_digit() {
+                    break;
                 }
+                number.push(d);
+                chars.next();
             }
-            c if c.is_ascii_digit() => match step {
-                S::ReadingValue1 => {
-                    let digit = c.to_digit(10).unwrap() as i32;
-                    value1 = match value1.checked_mul(10) {
-                        Some(value1) => match value1.checked_add(digit) {
-                            Some(value1) => value1,
-                            None => return ER::InputNumberOverflow,
-                        },
-                        None => return ER::InputNumberOverflow,
-                    };
+            tokens.push(number);
+        } else if c == '(' || c == ')' || c.is_valid_rpn_operator() {
+            tokens.push(c.to_string());
+            chars.next();
+        } else {
+            return Err(ER::InvalidCharacterFound(c));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Converts an infix expression like `(1 + 2) * 3` into the RPN form `evaluate_rpn` understands,
+/// using Dijkstra's shunting-yard algorithm.
+pub fn infix_to_rpn(input: &str) -> Result<String, EvaluationResult> {
+    use EvaluationResult as ER;
+
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ER::InputEmpty);
+    }
+
+    let mut output: Vec<String> = Vec::new();
+    let mut operators: Vec<char> = Vec::new();
+
+    for token in tokenize_infix(input)? {
+        let first = token.chars().next().unwrap();
+
+        if first.is_ascii_digit() {
+            output.push(token);
+        } else if first == '(' {
+            operators.push('(');
+        } else if first == ')' {
+            loop {
+                match operators.pop() {
+                    Some('(') => break,
+                    Some(op) => output.push(op.to_string()),
+                    None => return Err(ER::MismatchedParentheses),
                 }
-                S::ReadingValue2 => {
-                    let digit = c.to_digit(10).unwrap() as i32;
-                    value2 = match value2.checked_mul(10) {
-                        Some(value2) => match value2.checked_add(digit) {
-                            Some(value2) => value2,
-                            None => return ER::InputNumberOverflow,
-                        },
-                        None => return ER::InputNumberOverflow,
-                    };
+            }
+        } else {
+            let incoming = RpnOperator::try_from(first)?;
+            while let Some(&top) = operators.last() {
+                if top == '(' {
+                    break;
                 }
-                S::ReadingOperator => return ER::FoundNonOperator(c),
-            },
-            c if c.is_valid_rpn_operator() => {
-                use RpnOperator as OP;
-                let o = OP::try_from(c).unwrap();
-                match step {
-                    S::ReadingOperator => match o {
-                        OP::Addition => {
-                            value1 = match value1.checked_add(value2) {
-                                Some(res) => res,
-                                None => {
-                                    return ER::Overflow {
-                                        last_valid_value1: value1,
-                                        last_valid_value2: value2,
-                                        attempted_operation: o,
-                                    }
-                                }
-                            }
-                        }
-                        OP::Subtraction => {
-                            value1 = match value1.checked_sub(value2) {
-                                Some(res) => res,
-                                None => {
-                                    return ER::Overflow {
-                                        last_valid_value1: value1,
-                                        last_valid_value2: value2,
-                                        attempted_operation: o,
-                                    }
-                                }
-                            }
-                        }
-                        OP::Multiplication => {
-                            value1 = match value1.checked_mul(value2) {
-                                Some(res) => res,
-                                None => {
-                                    return ER::Overflow {
-                                        last_valid_value1: value1,
-                                        last_valid_value2: value2,
-                                        attempted_operation: o,
-                                    }
-                                }
-                            }
-                        }
-                        OP::Division => {
-                            value1 = match value1.checked_div(value2) {
-                                Some(res) => res,
-                                None => {
-                                    if value2 == 0 {
-                                        return ER::DivByZero;
-                                    } else {
-                                        return ER::Overflow {
-                                            last_valid_value1: value1,
-                                            last_valid_value2: value2,
-                                            attempted_operation: o,
-                                        };
-                                    }
-                                }
-                            }
-                        }
-                    },
-                    S::ReadingValue1 | S::ReadingValue2 => return ER::FoundNonDigit(c),
+                let top_operator = RpnOperator::try_from(top).unwrap();
+                if top_operator.precedence() >= incoming.precedence() {
+                    output.push(operators.pop().unwrap().to_string());
+                } else {
+                    break;
                 }
             }
-            invalid => return ER::InvalidCharacterFound(invalid),
+            operators.push(first);
+        }
+    }
+
+    while let Some(op) = operators.pop() {
+        if op == '(' {
+            return Err(ER::MismatchedParentheses);
         }
+        output.push(op.to_string());
     }
 
-    ER::Success(value1)
+    Ok(output.join(" "))
+}
+
+/// Convenience wrapper that converts infix to RPN and evaluates it in one call.
+pub fn evaluate_infix(input: &str) -> EvaluationResult {
+    match infix_to_rpn(input) {
+        Ok(rpn) => evaluate_rpn(&rpn),
+        Err(e) => e,
+    }
+}
+
+/// A single instruction in a compiled RPN program, executed by `run`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OpCode {
+    Push(i32),
+    Binary(RpnOperator),
+    Unary(UnaryOp),
+    Compare(CmpOp),
+}
+
+/// Compiles an RPN string into a `Vec<OpCode>` that `run` can execute, separating
+/// parsing from execution so the same program can be run repeatedly without
+/// re-tokenizing the source text.
+pub fn compile(input: &str) -> Result<Vec<OpCode>, EvaluationResult> {
+    use EvaluationResult as ER;
+
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ER::InputEmpty);
+    }
+
+    let mut program = Vec::new();
+
+    for token in input.split_whitespace() {
+        if let Ok(unary) = UnaryOp::try_from(token) {
+            program.push(OpCode::Unary(unary));
+            continue;
+        }
+
+        if token.len() == 1 {
+            let c = token.chars().next().unwrap();
+            if let Ok(cmp) = CmpOp::try_from(c) {
+                program.push(OpCode::Compare(cmp));
+                continue;
+            }
+            if let Ok(op) = RpnOperator::try_from(c) {
+                program.push(OpCode::Binary(op));
+                continue;
+            }
+        }
+
+        program.push(OpCode::Push(parse_number(token, OverflowMode::Checked)?));
+    }
+
+    Ok(program)
+}
+
+/// Executes a compiled program over an operand stack, returning the same
+/// `EvaluationResult` variants the string-based evaluators do.
+pub fn run(program: &[OpCode]) -> EvaluationResult {
+    use EvaluationResult as ER;
+
+    let mut stack: Vec<i32> = Vec::new();
+
+    for op in program {
+        match *op {
+            OpCode::Push(value) => stack.push(value),
+            OpCode::Unary(u) => {
+                let value = match stack.pop() {
+                    Some(v) => v,
+                    None => return ER::StackUnderflow,
+                };
+                let result = match u {
+                    UnaryOp::Neg => value.checked_neg(),
+                    UnaryOp::Abs => value.checked_abs(),
+                };
+                match result {
+                    Some(v) => stack.push(v),
+                    None => return ER::InputNumberOverflow,
+                }
+            }
+            OpCode::Compare(cmp) => {
+                let rhs = match stack.pop() {
+                    Some(v) => v,
+                    None => return ER::StackUnderflow,
+                };
+                let lhs = match stack.pop() {
+                    Some(v) => v,
+                    None => return ER::StackUnderflow,
+                };
+                let truth = match cmp {
+                    CmpOp::LessThan => lhs < rhs,
+                    CmpOp::GreaterThan => lhs > rhs,
+                    CmpOp::Equal => lhs == rhs,
+                };
+                stack.push(truth as i32);
+            }
+            OpCode::Binary(o) => {
+                let rhs = match stack.pop() {
+                    Some(v) => v,
+                    None => return ER::StackUnderflow,
+                };
+                let lhs = match stack.pop() {
+                    Some(v) => v,
+                    None => return ER::StackUnderflow,
+                };
+                match apply_operator(o, lhs, rhs, OverflowMode::Checked) {
+                    Ok(result) => stack.push(result),
+                    Err(e) => return e,
+                }
+            }
+        }
+    }
+
+    match stack.len() {
+        1 => ER::Success(stack[0]),
+        _ => ER::InputNotComplete,
+    }
 }
 
 #[cfg(test)]
@@ -300,4 +743,231 @@ mod tests {
         let input = "2004 6 /";
         assert_eq!(evaluate_rpn(input), ER::Success(334));
     }
+
+    #[test]
+    fn nested_addition_needs_a_real_stack() {
+        let input = "1 2 3 + +";
+        assert_eq!(evaluate_rpn(input), ER::Success(6));
+    }
+
+    #[test]
+    fn deeply_nested_expression() {
+        let input = "1 2 + 3 4 + *";
+        assert_eq!(evaluate_rpn(input), ER::Success(21));
+    }
+
+    #[test]
+    fn operator_with_too_few_operands() {
+        let input = "1 +";
+        assert_eq!(evaluate_rpn(input), ER::StackUnderflow);
+    }
+
+    #[test]
+    fn leftover_values_are_incomplete() {
+        let input = "1 2";
+        assert_eq!(evaluate_rpn(input), ER::InputNotComplete);
+    }
+
+    #[test]
+    fn infix_to_rpn_simple_addition() {
+        assert_eq!(infix_to_rpn("1 + 2"), Ok("1 2 +".to_string()));
+    }
+
+    #[test]
+    fn infix_to_rpn_respects_precedence() {
+        assert_eq!(infix_to_rpn("1 + 2 * 3"), Ok("1 2 3 * +".to_string()));
+    }
+
+    #[test]
+    fn infix_to_rpn_respects_parentheses() {
+        assert_eq!(infix_to_rpn("(1 + 2) * 3"), Ok("1 2 + 3 *".to_string()));
+    }
+
+    #[test]
+    fn infix_to_rpn_mismatched_closing_paren() {
+        assert_eq!(infix_to_rpn("1 + 2)"), Err(ER::MismatchedParentheses));
+    }
+
+    #[test]
+    fn infix_to_rpn_unclosed_paren() {
+        assert_eq!(infix_to_rpn("(1 + 2"), Err(ER::MismatchedParentheses));
+    }
+
+    #[test]
+    fn evaluate_infix_parenthesized_expression() {
+        let input = "(1 + 2) * 3";
+        assert_eq!(evaluate_infix(input), ER::Success(9));
+    }
+
+    #[test]
+    fn checked_mode_still_overflows() {
+        let input = format!("{} 1 +", i32::MAX);
+        assert_eq!(
+            evaluate_rpn_with(&input, OverflowMode::Checked),
+            ER::Overflow {
+                last_valid_value1: i32::MAX,
+                last_valid_value2: 1,
+                attempted_operation: RpnOperator::Addition,
+            }
+        );
+    }
+
+    #[test]
+    fn wrapping_mode_wraps_around() {
+        let input = format!("{} 1 +", i32::MAX);
+        assert_eq!(
+            evaluate_rpn_with(&input, OverflowMode::Wrapping),
+            ER::Success(i32::MIN)
+        );
+    }
+
+    #[test]
+    fn saturating_mode_clamps_to_max() {
+        let input = format!("{} 1 +", i32::MAX);
+        assert_eq!(
+            evaluate_rpn_with(&input, OverflowMode::Saturating),
+            ER::Success(i32::MAX)
+        );
+    }
+
+    #[test]
+    fn wrapping_chain_multiplication_matches_checked_when_in_range() {
+        let input = "1 2 * 3 * 4 * 5 * 6 * 7 *";
+        assert_eq!(
+            evaluate_rpn_with(input, OverflowMode::Wrapping),
+            ER::Success(5040)
+        );
+    }
+
+    #[test]
+    fn i64_evaluator_handles_values_too_big_for_i32() {
+        let input = "4294967296 2 *";
+        assert_eq!(evaluate_rpn_i64(input), ER::Success(8_589_934_592));
+    }
+
+    #[test]
+    fn i64_evaluator_still_checks_for_overflow() {
+        let input = format!("{} 1 +", i64::MAX);
+        assert_eq!(
+            evaluate_rpn_i64(&input),
+            ER::Overflow {
+                last_valid_value1: i64::MAX,
+                last_valid_value2: 1,
+                attempted_operation: RpnOperator::Addition,
+            }
+        );
+    }
+
+    #[test]
+    fn i64_evaluator_supports_wrapping_mode() {
+        let input = format!("{} 1 +", i64::MAX);
+        assert_eq!(
+            evaluate_rpn_i64_with(&input, OverflowMode::Wrapping),
+            ER::Success(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn i64_evaluator_supports_hex_literals() {
+        let input = "0xFF 0x1 +";
+        assert_eq!(evaluate_rpn_i64(input), ER::Success(256));
+    }
+
+    #[test]
+    fn f64_evaluator_handles_decimal_literals() {
+        let input = "3.5 2 /";
+        assert_eq!(evaluate_rpn_f64(input), ER::Success(1.75));
+    }
+
+    #[test]
+    fn f64_evaluator_division_by_zero_is_not_an_error() {
+        let input = "1 0 /";
+        assert_eq!(evaluate_rpn_f64(input), ER::Success(f64::INFINITY));
+    }
+
+    #[test]
+    fn modulo_operator() {
+        let input = "10 3 %";
+        assert_eq!(evaluate_rpn(input), ER::Success(1));
+    }
+
+    #[test]
+    fn compile_then_run_matches_evaluate_rpn() {
+        let program = compile("1 2 3 + +").unwrap();
+        assert_eq!(run(&program), ER::Success(6));
+    }
+
+    #[test]
+    fn compiled_program_runs_repeatedly() {
+        let program = compile("3 4 +").unwrap();
+        assert_eq!(run(&program), ER::Success(7));
+        assert_eq!(run(&program), ER::Success(7));
+    }
+
+    #[test]
+    fn run_supports_unary_neg_and_abs() {
+        let program = compile("5 neg abs").unwrap();
+        assert_eq!(run(&program), ER::Success(5));
+    }
+
+    #[test]
+    fn run_supports_comparisons() {
+        assert_eq!(run(&compile("1 2 <").unwrap()), ER::Success(1));
+        assert_eq!(run(&compile("2 1 <").unwrap()), ER::Success(0));
+        assert_eq!(run(&compile("3 3 =").unwrap()), ER::Success(1));
+        assert_eq!(run(&compile("4 3 >").unwrap()), ER::Success(1));
+    }
+
+    #[test]
+    fn run_supports_modulo() {
+        let program = compile("10 3 %").unwrap();
+        assert_eq!(run(&program), ER::Success(1));
+    }
+
+    #[test]
+    fn compile_rejects_unknown_token() {
+        assert_eq!(compile("1 frobnicate"), Err(ER::FoundNonDigit('f')));
+    }
+
+    #[test]
+    fn hex_literal() {
+        assert_eq!(evaluate_rpn("0xFF"), ER::Success(255));
+    }
+
+    #[test]
+    fn octal_literal() {
+        assert_eq!(evaluate_rpn("0o17"), ER::Success(15));
+    }
+
+    #[test]
+    fn binary_literal() {
+        assert_eq!(evaluate_rpn("0b101"), ER::Success(5));
+    }
+
+    #[test]
+    fn mixed_radix_addition() {
+        assert_eq!(evaluate_rpn("0xFF 0b1 +"), ER::Success(256));
+    }
+
+    #[test]
+    fn invalid_digit_for_radix() {
+        assert_eq!(evaluate_rpn("0b12"), ER::InvalidDigitForRadix('2'));
+    }
+
+    #[test]
+    fn plain_base_ten_typo_is_not_confused_with_a_radix_error() {
+        assert_eq!(evaluate_rpn("5x"), ER::FoundNonDigit('x'));
+    }
+
+    #[test]
+    fn bare_radix_prefix_without_digits_is_rejected() {
+        assert_eq!(evaluate_rpn("0x"), ER::InvalidDigitForRadix('x'));
+        assert_eq!(evaluate_rpn("0o"), ER::InvalidDigitForRadix('o'));
+        assert_eq!(evaluate_rpn("0b"), ER::InvalidDigitForRadix('b'));
+    }
+
+    #[test]
+    fn character_that_is_neither_digit_nor_operator_is_invalid_character() {
+        assert_eq!(evaluate_rpn("1 $ 2"), ER::InvalidCharacterFound('$'));
+    }
 }